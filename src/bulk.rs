@@ -0,0 +1,192 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(not(feature = "async"))]
+use std::sync::Mutex;
+
+#[cfg(feature = "async")]
+use tokio::sync::Semaphore;
+
+use crate::errors::Result;
+use crate::info::Info;
+use crate::players::Player;
+use crate::rules::Rule;
+use crate::A2SClient;
+
+const DEFAULT_CONCURRENCY: usize = 32;
+
+/// Builder returned by [`A2SClient::query_many`] for querying many servers
+/// concurrently, with a bounded number of requests in flight.
+pub struct QueryManyBuilder {
+    concurrency: usize,
+    timeout: Duration,
+}
+
+impl A2SClient {
+    pub fn query_many(&self) -> QueryManyBuilder {
+        QueryManyBuilder {
+            concurrency: DEFAULT_CONCURRENCY,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl QueryManyBuilder {
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+#[cfg(not(feature = "async"))]
+impl QueryManyBuilder {
+    pub fn info(&self, addrs: impl IntoIterator<Item = SocketAddr>) -> Vec<(SocketAddr, Result<Info>)> {
+        self.run(addrs, |client, addr| client.info(addr))
+    }
+
+    pub fn players(
+        &self,
+        addrs: impl IntoIterator<Item = SocketAddr>,
+    ) -> Vec<(SocketAddr, Result<Vec<Player>>)> {
+        self.run(addrs, |client, addr| client.players(addr))
+    }
+
+    pub fn rules(&self, addrs: impl IntoIterator<Item = SocketAddr>) -> Vec<(SocketAddr, Result<Vec<Rule>>)> {
+        self.run(addrs, |client, addr| client.rules(addr))
+    }
+
+    fn run<T: Send + 'static>(
+        &self,
+        addrs: impl IntoIterator<Item = SocketAddr>,
+        query: impl Fn(&A2SClient, SocketAddr) -> Result<T> + Send + Copy + 'static,
+    ) -> Vec<(SocketAddr, Result<T>)> {
+        let queue = Arc::new(Mutex::new(addrs.into_iter().collect::<Vec<_>>()));
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let timeout = self.timeout;
+
+        let handles: Vec<_> = (0..self.concurrency)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                let results = Arc::clone(&results);
+
+                std::thread::spawn(move || loop {
+                    let addr = match queue.lock().unwrap().pop() {
+                        Some(addr) => addr,
+                        None => break,
+                    };
+
+                    let result = A2SClient::new().and_then(|mut client| {
+                        client.set_timeout(timeout)?;
+                        query(&client, addr)
+                    });
+
+                    results.lock().unwrap().push((addr, result));
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let _ = handle.join();
+        }
+
+        Arc::try_unwrap(results)
+            .unwrap_or_else(|_| unreachable!("all worker threads have joined"))
+            .into_inner()
+            .unwrap()
+    }
+}
+
+#[cfg(feature = "async")]
+impl QueryManyBuilder {
+    pub async fn info(&self, addrs: impl IntoIterator<Item = SocketAddr>) -> Vec<(SocketAddr, Result<Info>)> {
+        self.run(addrs, |client, addr| Box::pin(async move { client.info(addr).await }))
+            .await
+    }
+
+    pub async fn players(
+        &self,
+        addrs: impl IntoIterator<Item = SocketAddr>,
+    ) -> Vec<(SocketAddr, Result<Vec<Player>>)> {
+        self.run(addrs, |client, addr| Box::pin(async move { client.players(addr).await }))
+            .await
+    }
+
+    pub async fn rules(&self, addrs: impl IntoIterator<Item = SocketAddr>) -> Vec<(SocketAddr, Result<Vec<Rule>>)> {
+        self.run(addrs, |client, addr| Box::pin(async move { client.rules(addr).await }))
+            .await
+    }
+
+    async fn run<T: Send + 'static, F>(
+        &self,
+        addrs: impl IntoIterator<Item = SocketAddr>,
+        query: impl Fn(A2SClient, SocketAddr) -> F + Send + Copy + 'static,
+    ) -> Vec<(SocketAddr, Result<T>)>
+    where
+        F: std::future::Future<Output = Result<T>> + Send + 'static,
+    {
+        let addrs: Vec<SocketAddr> = addrs.into_iter().collect();
+        let semaphore = Arc::new(Semaphore::new(self.concurrency));
+        let timeout = self.timeout;
+
+        let mut tasks = Vec::with_capacity(addrs.len());
+
+        for addr in addrs {
+            let semaphore = Arc::clone(&semaphore);
+
+            tasks.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire_owned().await.expect("semaphore is never closed");
+
+                let result = match A2SClient::new().await {
+                    Ok(mut client) => {
+                        client.set_timeout(timeout);
+                        query(client, addr).await
+                    }
+                    Err(e) => Err(e),
+                };
+
+                (addr, result)
+            }));
+        }
+
+        let mut results = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            if let Ok(result) = task.await {
+                results.push(result);
+            }
+        }
+
+        results
+    }
+}
+
+#[cfg(all(test, not(feature = "async")))]
+mod tests {
+    use super::*;
+    use std::net::UdpSocket;
+
+    fn unused_addr() -> SocketAddr {
+        // Bound and immediately dropped, so nothing answers on this port.
+        UdpSocket::bind("127.0.0.1:0").unwrap().local_addr().unwrap()
+    }
+
+    #[test]
+    fn one_dead_host_does_not_abort_the_batch() {
+        let client = A2SClient::new().unwrap();
+        let addrs = vec![unused_addr(), unused_addr()];
+
+        let results = client
+            .query_many()
+            .timeout(Duration::from_millis(50))
+            .concurrency(2)
+            .rules(addrs.clone());
+
+        assert_eq!(results.len(), addrs.len());
+        assert!(results.iter().all(|(_, r)| r.is_err()));
+    }
+}
@@ -0,0 +1,194 @@
+use std::io::{Cursor, Read};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::errors::{Error, Result};
+use crate::info::{Info, INFO_REQUEST};
+use crate::players::{self, Player, PLAYERS_REQUEST};
+use crate::rules::{Rule, RULES_REQUEST};
+
+const SINGLE_PACKET_HEADER: [u8; 4] = [0xFF, 0xFF, 0xFF, 0xFF];
+const CHALLENGE_REQUEST: u8 = 0x57;
+const CHALLENGE_RESPONSE: u8 = 0x41;
+
+/// The typed requests an [`A2SCodec`] knows how to encode.
+#[derive(Debug, Clone, Copy)]
+pub enum A2SRequest {
+    Info,
+    Players,
+    Rules,
+    /// A standalone `A2S_SERVERQUERY_GETCHALLENGE` request, carrying the
+    /// challenge value to echo back (`0xFFFFFFFF` to request a fresh one).
+    Challenge(u32),
+}
+
+/// The typed responses an [`A2SCodec`] decodes, mirroring [`A2SRequest`].
+#[derive(Debug, Clone)]
+pub enum A2SResponse {
+    Info(Info),
+    Players(Vec<Player>),
+    Rules(Vec<Rule>),
+    Challenge(u32),
+}
+
+/// A `tokio_util::codec` adaptor over raw A2S datagrams, reusing the same
+/// request bytes and `from_cursor` parsers the blocking/async
+/// [`crate::A2SClient`] methods use. Each call to [`Decoder::decode`] expects a single complete
+/// datagram (e.g. from a `UdpFramed`); split or compressed responses must be
+/// reassembled by the caller before being handed to this codec.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct A2SCodec;
+
+impl Encoder<A2SRequest> for A2SCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: A2SRequest, dst: &mut BytesMut) -> Result<()> {
+        match item {
+            A2SRequest::Info => dst.extend_from_slice(&INFO_REQUEST),
+            A2SRequest::Players => dst.extend_from_slice(&PLAYERS_REQUEST),
+            A2SRequest::Rules => dst.extend_from_slice(&RULES_REQUEST),
+            A2SRequest::Challenge(challenge) => {
+                dst.extend_from_slice(&SINGLE_PACKET_HEADER);
+                dst.extend_from_slice(&[CHALLENGE_REQUEST]);
+                dst.extend_from_slice(&challenge.to_le_bytes());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Decoder for A2SCodec {
+    type Item = A2SResponse;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        if src.is_empty() {
+            return Ok(None);
+        }
+
+        let datagram = src.split_to(src.len());
+
+        let mut cursor = Cursor::new(datagram.as_ref());
+        if cursor.read_u32::<LittleEndian>()? != 0xFFFFFFFF {
+            return Err(Error::InvalidResponse);
+        }
+
+        let mut body = Vec::new();
+        cursor.read_to_end(&mut body)?;
+
+        match body.first() {
+            Some(0x49) => Ok(Some(A2SResponse::Info(Info::from_cursor(Cursor::new(body))?))),
+            Some(0x44) => Ok(Some(A2SResponse::Players(players::players_from_cursor(
+                Cursor::new(body),
+            )?))),
+            Some(0x45) => Ok(Some(A2SResponse::Rules(Rule::from_cursor(Cursor::new(body))?))),
+            Some(&CHALLENGE_RESPONSE) => {
+                let challenge = Cursor::new(&body[1..]).read_u32::<LittleEndian>()?;
+                Ok(Some(A2SResponse::Challenge(challenge)))
+            }
+            _ => Err(Error::InvalidResponse),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_rules_request() {
+        let mut buf = BytesMut::new();
+        A2SCodec.encode(A2SRequest::Rules, &mut buf).unwrap();
+
+        assert_eq!(&buf[..], &RULES_REQUEST);
+    }
+
+    #[test]
+    fn encodes_challenge_request() {
+        let mut buf = BytesMut::new();
+        A2SCodec.encode(A2SRequest::Challenge(0xFFFFFFFF), &mut buf).unwrap();
+
+        assert_eq!(&buf[..], &[0xFF, 0xFF, 0xFF, 0xFF, 0x57, 0xFF, 0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn decodes_challenge_response() {
+        let mut buf = BytesMut::from(&[0xFF, 0xFF, 0xFF, 0xFF, 0x41, 0x01, 0x02, 0x03, 0x04][..]);
+
+        let response = A2SCodec.decode(&mut buf).unwrap().unwrap();
+
+        assert!(matches!(response, A2SResponse::Challenge(0x04030201)));
+    }
+
+    #[test]
+    fn decodes_rules_response() {
+        let mut bytes = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x45];
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(b"sv_gravity\0");
+        bytes.extend_from_slice(b"800\0");
+
+        let mut buf = BytesMut::from(&bytes[..]);
+        let response = A2SCodec.decode(&mut buf).unwrap().unwrap();
+
+        match response {
+            A2SResponse::Rules(rules) => assert_eq!(rules.len(), 1),
+            other => panic!("expected Rules response, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_info_response() {
+        let info = Info {
+            protocol: 17,
+            name: "My Server".to_string(),
+            map: "de_dust2".to_string(),
+            folder: "csgo".to_string(),
+            game: "Counter-Strike: Global Offensive".to_string(),
+            app_id: 730,
+            players: 5,
+            max_players: 10,
+            bots: 0,
+            server_type: b'd',
+            environment: b'l',
+            visibility: 0,
+            vac: 1,
+            version: "1.38.0.0".to_string(),
+        };
+
+        let mut buf = BytesMut::from(&info.to_bytes()[..]);
+        let response = A2SCodec.decode(&mut buf).unwrap().unwrap();
+
+        match response {
+            A2SResponse::Info(decoded) => assert_eq!(decoded.name, info.name),
+            other => panic!("expected Info response, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_players_response() {
+        let players = vec![Player {
+            index: 0,
+            name: "alice".to_string(),
+            score: 12,
+            duration: 345.6,
+        }];
+
+        let mut buf = BytesMut::from(&players::players_to_bytes(players)[..]);
+        let response = A2SCodec.decode(&mut buf).unwrap().unwrap();
+
+        match response {
+            A2SResponse::Players(decoded) => assert_eq!(decoded.len(), 1),
+            other => panic!("expected Players response, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_missing_header() {
+        let mut buf = BytesMut::from(&[0x01, 0x02, 0x03, 0x04, 0x45][..]);
+
+        assert!(matches!(A2SCodec.decode(&mut buf), Err(Error::InvalidResponse)));
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+use std::io;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    InvalidResponse,
+    BadPacketID,
+    DecompressionFailed,
+    ChecksumMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::InvalidResponse => write!(f, "invalid response from server"),
+            Error::BadPacketID => write!(f, "packets in a split response had mismatched IDs"),
+            Error::DecompressionFailed => write!(f, "failed to decompress bzip2 split response"),
+            Error::ChecksumMismatch => {
+                write!(f, "decompressed split response failed its CRC32 check")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
@@ -0,0 +1,104 @@
+use std::io::Cursor;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+#[cfg(not(feature = "async"))]
+use std::net::ToSocketAddrs;
+#[cfg(feature = "async")]
+use tokio::net::ToSocketAddrs;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Error, Result};
+use crate::{A2SClient, ReadBytes};
+
+pub(crate) const INFO_REQUEST: [u8; 25] = [
+    0xFF, 0xFF, 0xFF, 0xFF, 0x54, b'S', b'o', b'u', b'r', b'c', b'e', b' ', b'E', b'n', b'g', b'i',
+    b'n', b'e', b' ', b'Q', b'u', b'e', b'r', b'y', 0x00,
+];
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Info {
+    pub protocol: u8,
+    pub name: String,
+    pub map: String,
+    pub folder: String,
+    pub game: String,
+    pub app_id: u16,
+    pub players: u8,
+    pub max_players: u8,
+    pub bots: u8,
+    pub server_type: u8,
+    pub environment: u8,
+    pub visibility: u8,
+    pub vac: u8,
+    pub version: String,
+}
+
+impl Info {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend(&[0xff, 0xff, 0xff, 0xff, 0x49]);
+        bytes.push(self.protocol);
+        bytes.extend(self.name.as_bytes());
+        bytes.push(0);
+        bytes.extend(self.map.as_bytes());
+        bytes.push(0);
+        bytes.extend(self.folder.as_bytes());
+        bytes.push(0);
+        bytes.extend(self.game.as_bytes());
+        bytes.push(0);
+        bytes.extend(self.app_id.to_le_bytes());
+        bytes.push(self.players);
+        bytes.push(self.max_players);
+        bytes.push(self.bots);
+        bytes.push(self.server_type);
+        bytes.push(self.environment);
+        bytes.push(self.visibility);
+        bytes.push(self.vac);
+        bytes.extend(self.version.as_bytes());
+        bytes.push(0);
+
+        bytes
+    }
+
+    pub fn from_cursor(mut data: Cursor<Vec<u8>>) -> Result<Self> {
+        if data.read_u8()? != 0x49 {
+            return Err(Error::InvalidResponse);
+        }
+
+        Ok(Self {
+            protocol: data.read_u8()?,
+            name: String::from_utf8_lossy(&data.read_bytes_nullterm()?).to_string(),
+            map: String::from_utf8_lossy(&data.read_bytes_nullterm()?).to_string(),
+            folder: String::from_utf8_lossy(&data.read_bytes_nullterm()?).to_string(),
+            game: String::from_utf8_lossy(&data.read_bytes_nullterm()?).to_string(),
+            app_id: data.read_u16::<LittleEndian>()?,
+            players: data.read_u8()?,
+            max_players: data.read_u8()?,
+            bots: data.read_u8()?,
+            server_type: data.read_u8()?,
+            environment: data.read_u8()?,
+            visibility: data.read_u8()?,
+            vac: data.read_u8()?,
+            version: String::from_utf8_lossy(&data.read_bytes_nullterm()?).to_string(),
+        })
+    }
+}
+
+impl A2SClient {
+    #[cfg(feature = "async")]
+    pub async fn info<A: ToSocketAddrs>(&self, addr: A) -> Result<Info> {
+        let data = self.do_challenge_request(addr, &INFO_REQUEST).await?;
+        Info::from_cursor(Cursor::new(data))
+    }
+
+    #[cfg(not(feature = "async"))]
+    pub fn info<A: ToSocketAddrs>(&self, addr: A) -> Result<Info> {
+        let data = self.do_challenge_request(addr, &INFO_REQUEST)?;
+        Info::from_cursor(Cursor::new(data))
+    }
+}
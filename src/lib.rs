@@ -0,0 +1,250 @@
+use std::io::{Cursor, Read};
+#[cfg(not(feature = "async"))]
+use std::net::{ToSocketAddrs, UdpSocket};
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+#[cfg(feature = "async")]
+use tokio::net::{ToSocketAddrs, UdpSocket};
+
+pub mod bulk;
+#[cfg(feature = "codec")]
+pub mod codec;
+pub mod errors;
+pub mod info;
+pub mod players;
+mod reassembly;
+#[cfg(not(feature = "async"))]
+pub mod reactor;
+pub mod rules;
+
+pub use bulk::QueryManyBuilder;
+#[cfg(feature = "codec")]
+pub use codec::{A2SCodec, A2SRequest, A2SResponse};
+pub use errors::{Error, Result};
+pub use info::Info;
+pub use players::Player;
+#[cfg(not(feature = "async"))]
+pub use reactor::{PacketReassembler, PollResponse};
+pub use rules::Rule;
+
+pub(crate) const SINGLE_PACKET_HEADER: u32 = 0xFFFFFFFF;
+pub(crate) const SPLIT_PACKET_HEADER: u32 = 0xFFFFFFFE;
+const CHALLENGE_RESPONSE: u8 = 0x41;
+
+const MAX_PACKET_SIZE: usize = 17_984;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(3);
+
+pub struct A2SClient {
+    socket: UdpSocket,
+    max_size: usize,
+    timeout: Duration,
+}
+
+impl A2SClient {
+    #[cfg(not(feature = "async"))]
+    pub fn new() -> Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+
+        socket.set_read_timeout(Some(DEFAULT_TIMEOUT))?;
+        socket.set_write_timeout(Some(DEFAULT_TIMEOUT))?;
+
+        Ok(Self {
+            socket,
+            max_size: MAX_PACKET_SIZE,
+            timeout: DEFAULT_TIMEOUT,
+        })
+    }
+
+    #[cfg(feature = "async")]
+    pub async fn new() -> Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0").await?;
+
+        Ok(Self {
+            socket,
+            max_size: MAX_PACKET_SIZE,
+            timeout: DEFAULT_TIMEOUT,
+        })
+    }
+
+    #[cfg(not(feature = "async"))]
+    pub(crate) fn do_challenge_request<A: ToSocketAddrs>(
+        &self,
+        addr: A,
+        request: &[u8],
+    ) -> Result<Vec<u8>> {
+        self.socket.connect(addr)?;
+
+        self.socket.send(request)?;
+
+        let mut packet = self.recv_packet()?;
+
+        // Some servers reply with a challenge number before answering; when
+        // that happens, resend the request with the challenge appended.
+        if let Some(challenge) = Self::parse_challenge(&packet)? {
+            let mut challenged_request = request.to_vec();
+            challenged_request.extend(&challenge.to_le_bytes());
+
+            self.socket.send(&challenged_request)?;
+
+            packet = self.recv_packet()?;
+        }
+
+        self.reassemble(packet)
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn do_challenge_request<A: ToSocketAddrs>(
+        &self,
+        addr: A,
+        request: &[u8],
+    ) -> Result<Vec<u8>> {
+        self.socket.connect(addr).await?;
+
+        self.socket.send(request).await?;
+
+        let mut packet = self.recv_packet().await?;
+
+        if let Some(challenge) = Self::parse_challenge(&packet)? {
+            let mut challenged_request = request.to_vec();
+            challenged_request.extend(&challenge.to_le_bytes());
+
+            self.socket.send(&challenged_request).await?;
+
+            packet = self.recv_packet().await?;
+        }
+
+        self.reassemble(packet).await
+    }
+
+    #[cfg(not(feature = "async"))]
+    fn recv_packet(&self) -> Result<Vec<u8>> {
+        let mut buf = vec![0; self.max_size];
+        let read = self.socket.recv(&mut buf)?;
+        buf.truncate(read);
+        Ok(buf)
+    }
+
+    #[cfg(feature = "async")]
+    async fn recv_packet(&self) -> Result<Vec<u8>> {
+        let mut buf = vec![0; self.max_size];
+        let read = tokio::time::timeout(self.timeout, self.socket.recv(&mut buf))
+            .await
+            .map_err(|_| {
+                Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "timed out waiting for a response",
+                ))
+            })??;
+        buf.truncate(read);
+        Ok(buf)
+    }
+
+    /// Overrides the per-request timeout set by [`A2SClient::new`].
+    #[cfg(not(feature = "async"))]
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<()> {
+        self.socket.set_read_timeout(Some(timeout))?;
+        self.socket.set_write_timeout(Some(timeout))?;
+        self.timeout = timeout;
+        Ok(())
+    }
+
+    /// Overrides the per-request timeout set by [`A2SClient::new`].
+    #[cfg(feature = "async")]
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    pub(crate) fn parse_challenge(buf: &[u8]) -> Result<Option<u32>> {
+        let mut cursor = Cursor::new(buf);
+
+        if cursor.read_u32::<LittleEndian>()? != SINGLE_PACKET_HEADER {
+            return Ok(None);
+        }
+
+        if cursor.read_u8()? != CHALLENGE_RESPONSE {
+            return Ok(None);
+        }
+
+        Ok(Some(cursor.read_u32::<LittleEndian>()?))
+    }
+
+    /// Reassembles (and, if needed, bzip2-decompresses) a split response,
+    /// then strips the leading `0xFFFFFFFF` single-packet header so callers
+    /// see the same shape regardless of how the response arrived on the
+    /// wire.
+    #[cfg(not(feature = "async"))]
+    fn reassemble(&self, first_packet: Vec<u8>) -> Result<Vec<u8>> {
+        let header = Cursor::new(&first_packet).read_u32::<LittleEndian>()?;
+
+        let data = if header == SINGLE_PACKET_HEADER {
+            first_packet
+        } else if header == SPLIT_PACKET_HEADER {
+            let total = *first_packet.get(8).ok_or(Error::InvalidResponse)? as usize;
+
+            let mut packets = vec![first_packet];
+            while packets.len() < total {
+                packets.push(self.recv_packet()?);
+            }
+
+            reassembly::reassemble_split_packets(packets)?
+        } else {
+            return Err(Error::InvalidResponse);
+        };
+
+        Ok(data[4..].to_vec())
+    }
+
+    #[cfg(feature = "async")]
+    async fn reassemble(&self, first_packet: Vec<u8>) -> Result<Vec<u8>> {
+        let header = Cursor::new(&first_packet).read_u32::<LittleEndian>()?;
+
+        let data = if header == SINGLE_PACKET_HEADER {
+            first_packet
+        } else if header == SPLIT_PACKET_HEADER {
+            let total = *first_packet.get(8).ok_or(Error::InvalidResponse)? as usize;
+
+            let mut packets = vec![first_packet];
+            while packets.len() < total {
+                packets.push(self.recv_packet().await?);
+            }
+
+            reassembly::reassemble_split_packets(packets)?
+        } else {
+            return Err(Error::InvalidResponse);
+        };
+
+        Ok(data[4..].to_vec())
+    }
+}
+
+/// Helpers for reading the null-terminated and fixed-size byte strings that
+/// show up throughout A2S responses.
+pub(crate) trait ReadBytes {
+    fn read_bytes_nullterm(&mut self) -> Result<Vec<u8>>;
+    fn read_bytes(&mut self, len: usize) -> Result<Vec<u8>>;
+}
+
+impl<T: Read> ReadBytes for T {
+    fn read_bytes_nullterm(&mut self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        let mut byte = [0u8; 1];
+
+        loop {
+            self.read_exact(&mut byte)?;
+            if byte[0] == 0 {
+                break;
+            }
+            bytes.push(byte[0]);
+        }
+
+        Ok(bytes)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<Vec<u8>> {
+        let mut bytes = vec![0u8; len];
+        self.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::Cursor;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+#[cfg(not(feature = "async"))]
+use std::net::ToSocketAddrs;
+#[cfg(feature = "async")]
+use tokio::net::ToSocketAddrs;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Error, Result};
+use crate::{A2SClient, ReadBytes};
+
+pub(crate) const PLAYERS_REQUEST: [u8; 5] = [0xFF, 0xFF, 0xFF, 0xFF, 0x55];
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Player {
+    pub index: u8,
+    pub name: String,
+    pub score: i32,
+    pub duration: f32,
+}
+
+impl Player {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.push(self.index);
+        bytes.extend(self.name.as_bytes());
+        bytes.push(0);
+        bytes.extend(self.score.to_le_bytes());
+        bytes.extend(self.duration.to_le_bytes());
+
+        bytes
+    }
+
+    pub fn from_cursor(data: &mut Cursor<Vec<u8>>) -> Result<Self> {
+        Ok(Self {
+            index: data.read_u8()?,
+            name: String::from_utf8_lossy(&data.read_bytes_nullterm()?).to_string(),
+            score: data.read_i32::<LittleEndian>()?,
+            duration: data.read_f32::<LittleEndian>()?,
+        })
+    }
+}
+
+pub fn players_to_bytes(players: Vec<Player>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+
+    bytes.extend(&[0xff, 0xff, 0xff, 0xff, 0x44]);
+    bytes.push(players.len() as u8);
+
+    for player in players {
+        bytes.extend(player.to_bytes());
+    }
+
+    bytes
+}
+
+pub fn players_from_cursor(mut data: Cursor<Vec<u8>>) -> Result<Vec<Player>> {
+    if data.read_u8()? != 0x44 {
+        return Err(Error::InvalidResponse);
+    }
+
+    let count = data.read_u8()?;
+
+    (0..count).map(|_| Player::from_cursor(&mut data)).collect()
+}
+
+impl A2SClient {
+    #[cfg(feature = "async")]
+    pub async fn players<A: ToSocketAddrs>(&self, addr: A) -> Result<Vec<Player>> {
+        let data = self.do_challenge_request(addr, &PLAYERS_REQUEST).await?;
+        players_from_cursor(Cursor::new(data))
+    }
+
+    #[cfg(not(feature = "async"))]
+    pub fn players<A: ToSocketAddrs>(&self, addr: A) -> Result<Vec<Player>> {
+        let data = self.do_challenge_request(addr, &PLAYERS_REQUEST)?;
+        players_from_cursor(Cursor::new(data))
+    }
+}
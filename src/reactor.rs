@@ -0,0 +1,158 @@
+//! Non-blocking, reactor-friendly alternative to the blocking query methods.
+
+use std::io::Cursor;
+
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+#[cfg(windows)]
+use std::os::windows::io::{AsRawSocket, RawSocket};
+
+#[cfg(not(feature = "async"))]
+use std::net::ToSocketAddrs;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::errors::Result;
+use crate::reassembly;
+use crate::A2SClient;
+
+/// What a caller gets back after handing a freshly-read datagram to
+/// [`A2SClient::recv_query`].
+#[derive(Debug, Clone)]
+pub enum PollResponse {
+    /// The server replied with a fresh challenge; resend the original
+    /// request with this value appended via [`A2SClient::send_query`].
+    Challenge(u32),
+    /// The response, reassembled and bzip2-decompressed if needed, with its
+    /// leading `0xFFFFFFFF` header already stripped, ready for the existing
+    /// `Rule::from_cursor` / `Info::from_cursor` / `players_from_cursor`.
+    Data(Vec<u8>),
+}
+
+/// Accumulates the datagrams of a split response across calls to
+/// [`A2SClient::recv_query`].
+#[derive(Debug, Default)]
+pub struct PacketReassembler {
+    total: Option<usize>,
+    packets: Vec<Vec<u8>>,
+}
+
+impl PacketReassembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn feed(&mut self, datagram: Vec<u8>) -> Result<Option<Vec<u8>>> {
+        let total = *datagram.get(8).ok_or(crate::Error::InvalidResponse)? as usize;
+        self.total.get_or_insert(total);
+        self.packets.push(datagram);
+
+        if self.packets.len() < self.total.unwrap_or(usize::MAX) {
+            return Ok(None);
+        }
+
+        let packets = std::mem::take(&mut self.packets);
+        self.total = None;
+
+        Ok(Some(reassembly::reassemble_split_packets(packets)?))
+    }
+}
+
+#[cfg(not(feature = "async"))]
+impl A2SClient {
+    /// Serializes and sends `request` (optionally with a challenge number
+    /// already appended), putting the socket in non-blocking mode first so
+    /// the caller's reactor owns the wait for a reply.
+    ///
+    /// This switches the socket to non-blocking mode for good: once called,
+    /// this client's `info`/`players`/`rules`/`do_challenge_request` will
+    /// fail with `WouldBlock` instead of blocking. Use a dedicated
+    /// `A2SClient` for reactor-driven queries rather than mixing the two
+    /// styles on the same client.
+    pub fn send_query<A: ToSocketAddrs>(&self, addr: A, request: &[u8]) -> Result<()> {
+        self.socket.set_nonblocking(true)?;
+        self.socket.connect(addr)?;
+        self.socket.send(request)?;
+        Ok(())
+    }
+
+    /// Attempts to parse a datagram the caller has already read off this
+    /// client's socket (e.g. after their reactor reported it readable).
+    /// Returns `Ok(None)` while a split response is still missing packets.
+    pub fn recv_query(
+        &self,
+        reassembler: &mut PacketReassembler,
+        datagram: Vec<u8>,
+    ) -> Result<Option<PollResponse>> {
+        let header = Cursor::new(&datagram).read_u32::<LittleEndian>()?;
+
+        if header == crate::SINGLE_PACKET_HEADER {
+            if let Some(challenge) = A2SClient::parse_challenge(&datagram)? {
+                return Ok(Some(PollResponse::Challenge(challenge)));
+            }
+
+            return Ok(Some(PollResponse::Data(datagram[4..].to_vec())));
+        }
+
+        Ok(reassembler
+            .feed(datagram)?
+            .map(|data| PollResponse::Data(data[4..].to_vec())))
+    }
+}
+
+#[cfg(all(not(feature = "async"), unix))]
+impl AsRawFd for A2SClient {
+    fn as_raw_fd(&self) -> RawFd {
+        self.socket.as_raw_fd()
+    }
+}
+
+#[cfg(all(not(feature = "async"), windows))]
+impl AsRawSocket for A2SClient {
+    fn as_raw_socket(&self) -> RawSocket {
+        self.socket.as_raw_socket()
+    }
+}
+
+#[cfg(all(test, not(feature = "async")))]
+mod tests {
+    use super::*;
+
+    fn split_packet(total: u8, number: u8, payload: &[u8]) -> Vec<u8> {
+        let mut packet = vec![0xFE, 0xFF, 0xFF, 0xFF, 1, 0, 0, 0, total, number];
+        packet.extend_from_slice(payload);
+        packet
+    }
+
+    #[test]
+    fn recv_query_waits_for_every_split_packet() {
+        let client = A2SClient::new().unwrap();
+        let mut reassembler = PacketReassembler::new();
+
+        let full = b"\xff\xff\xff\xff\x45hello world".to_vec();
+        let first = client
+            .recv_query(&mut reassembler, split_packet(2, 0, &full[..8]))
+            .unwrap();
+        assert!(first.is_none());
+
+        let second = client
+            .recv_query(&mut reassembler, split_packet(2, 1, &full[8..]))
+            .unwrap();
+
+        match second {
+            Some(PollResponse::Data(data)) => assert_eq!(data, full[4..]),
+            other => panic!("expected a completed Data response, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn recv_query_recognizes_a_challenge() {
+        let client = A2SClient::new().unwrap();
+        let mut reassembler = PacketReassembler::new();
+
+        let datagram = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x41, 0x01, 0x02, 0x03, 0x04];
+        let response = client.recv_query(&mut reassembler, datagram).unwrap();
+
+        assert!(matches!(response, Some(PollResponse::Challenge(0x04030201))));
+    }
+}
@@ -0,0 +1,168 @@
+use std::io::{Cursor, Read};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use bzip2::read::BzDecoder;
+
+use crate::errors::{Error, Result};
+
+const SPLIT_HEADER: u32 = 0xFFFFFFFE;
+
+/// A single datagram belonging to a split (multi-packet) A2S response, with
+/// its `0xFFFFFFFE` header and split fields parsed off; `payload` holds the
+/// bytes that follow them.
+struct SplitPacket {
+    id: u32,
+    number: u8,
+    payload: Vec<u8>,
+}
+
+fn parse_split_packet(mut data: Cursor<Vec<u8>>) -> Result<SplitPacket> {
+    if data.read_u32::<LittleEndian>()? != SPLIT_HEADER {
+        return Err(Error::InvalidResponse);
+    }
+
+    let id = data.read_u32::<LittleEndian>()?;
+    let _total = data.read_u8()?;
+    let number = data.read_u8()?;
+
+    let mut payload = Vec::new();
+    data.read_to_end(&mut payload)?;
+
+    Ok(SplitPacket { id, number, payload })
+}
+
+/// Reassembles the raw datagrams of a split response into a single buffer,
+/// transparently bzip2-decompressing it if the server set the compression
+/// bit (`id & 0x80000000`) on the split ID.
+///
+/// The returned buffer starts with the usual `0xFFFFFFFF` single-packet
+/// header, ready to be handed to the normal response parsers.
+pub(crate) fn reassemble_split_packets(raw_packets: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+    let mut packets: Vec<SplitPacket> = raw_packets
+        .into_iter()
+        .map(|raw| parse_split_packet(Cursor::new(raw)))
+        .collect::<Result<_>>()?;
+
+    packets.sort_by_key(|p| p.number);
+
+    let id = packets.first().ok_or(Error::InvalidResponse)?.id;
+    if packets.iter().any(|p| p.id != id) {
+        return Err(Error::BadPacketID);
+    }
+
+    if id & 0x80000000 == 0 {
+        return Ok(packets.into_iter().flat_map(|p| p.payload).collect());
+    }
+
+    // Compressed: the first packet's payload opens with the decompressed
+    // size and a CRC32 of the decompressed stream, then the bzip2 stream
+    // itself begins and continues across the remaining packets.
+    let mut first = Cursor::new(packets[0].payload.clone());
+    let decompressed_size = first.read_u32::<LittleEndian>()? as usize;
+    let crc = first.read_u32::<LittleEndian>()?;
+
+    let mut compressed = Vec::new();
+    first.read_to_end(&mut compressed)?;
+    for packet in &packets[1..] {
+        compressed.extend_from_slice(&packet.payload);
+    }
+
+    let mut decompressed = Vec::new();
+    BzDecoder::new(Cursor::new(compressed))
+        .read_to_end(&mut decompressed)
+        .map_err(|_| Error::DecompressionFailed)?;
+
+    if decompressed.len() != decompressed_size {
+        return Err(Error::DecompressionFailed);
+    }
+
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(&decompressed);
+    if hasher.finalize() != crc {
+        return Err(Error::ChecksumMismatch);
+    }
+
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byteorder::WriteBytesExt;
+    use bzip2::write::BzEncoder;
+    use bzip2::Compression;
+    use std::io::Write;
+
+    fn split_packet(id: u32, total: u8, number: u8, payload: &[u8]) -> Vec<u8> {
+        let mut packet = Vec::new();
+        packet.write_u32::<LittleEndian>(SPLIT_HEADER).unwrap();
+        packet.write_u32::<LittleEndian>(id).unwrap();
+        packet.push(total);
+        packet.push(number);
+        packet.extend_from_slice(payload);
+        packet
+    }
+
+    #[test]
+    fn reassembles_uncompressed_split_response() {
+        let full = b"\xff\xff\xff\xff\x45hello world".to_vec();
+        let packets = vec![
+            split_packet(1, 2, 0, &full[..8]),
+            split_packet(1, 2, 1, &full[8..]),
+        ];
+
+        let result = reassemble_split_packets(packets).unwrap();
+
+        assert_eq!(result, full);
+    }
+
+    #[test]
+    fn reassembles_compressed_split_response() {
+        let full = b"\xff\xff\xff\xff\x45hello compressed world".to_vec();
+
+        let mut encoder = BzEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&full).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(&full);
+        let crc = hasher.finalize();
+
+        let mut first_payload = Vec::new();
+        first_payload
+            .write_u32::<LittleEndian>(full.len() as u32)
+            .unwrap();
+        first_payload.write_u32::<LittleEndian>(crc).unwrap();
+        first_payload.extend_from_slice(&compressed);
+
+        let id = 1 | 0x80000000;
+        let packets = vec![split_packet(id, 1, 0, &first_payload)];
+
+        let result = reassemble_split_packets(packets).unwrap();
+
+        assert_eq!(result, full);
+    }
+
+    #[test]
+    fn rejects_mismatched_checksum() {
+        let full = b"\xff\xff\xff\xff\x45tampered".to_vec();
+
+        let mut encoder = BzEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&full).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut first_payload = Vec::new();
+        first_payload
+            .write_u32::<LittleEndian>(full.len() as u32)
+            .unwrap();
+        first_payload.write_u32::<LittleEndian>(0xdead_beef).unwrap();
+        first_payload.extend_from_slice(&compressed);
+
+        let id = 1 | 0x80000000;
+        let packets = vec![split_packet(id, 1, 0, &first_payload)];
+
+        let result = reassemble_split_packets(packets);
+
+        assert!(matches!(result, Err(Error::ChecksumMismatch)));
+    }
+}
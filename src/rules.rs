@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 use crate::errors::{Error, Result};
 use crate::{A2SClient, ReadBytes};
 
-const RULES_REQUEST: [u8; 5] = [0xFF, 0xFF, 0xFF, 0xFF, 0x56];
+pub(crate) const RULES_REQUEST: [u8; 5] = [0xFF, 0xFF, 0xFF, 0xFF, 0x56];
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]